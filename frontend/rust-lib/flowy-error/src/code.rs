@@ -0,0 +1,16 @@
+use flowy_derive::ProtoBuf_Enum;
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(i32)]
+pub enum ErrorCode {
+    Internal = 0,
+    ViewIdInvalid = 1,
+    UnexpectedEmptyString = 2,
+    DuplicateSettingId = 3,
+}
+
+impl std::default::Default for ErrorCode {
+    fn default() -> Self {
+        ErrorCode::Internal
+    }
+}
@@ -0,0 +1,3 @@
+mod code;
+
+pub use code::*;
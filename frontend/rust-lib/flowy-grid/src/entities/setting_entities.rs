@@ -1,11 +1,14 @@
 use crate::entities::parser::NotEmptyStr;
 use crate::entities::{
-    AlterFilterParams, AlterFilterPayloadPB, DeleteFilterParams, DeleteFilterPayloadPB, DeleteGroupParams,
-    DeleteGroupPayloadPB, InsertGroupParams, InsertGroupPayloadPB, RepeatedFilterPB, RepeatedGroupConfigurationPB,
+    AlterFilterParams, AlterFilterPayloadPB, AlterSortParams, AlterSortPayloadPB, DeleteFilterParams,
+    DeleteFilterPayloadPB, DeleteGroupParams, DeleteGroupPayloadPB, DeleteSortParams, DeleteSortPayloadPB,
+    InsertGroupParams, InsertGroupPayloadPB, RepeatedFilterPB, RepeatedGroupConfigurationPB, RepeatedSortPB,
+    SortConditionPB,
 };
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use flowy_error::ErrorCode;
 use grid_rev_model::LayoutRevision;
+use std::collections::HashSet;
 use std::convert::TryInto;
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
@@ -24,30 +27,112 @@ pub struct GridSettingPB {
 
     #[pb(index = 4)]
     pub group_configurations: RepeatedGroupConfigurationPB,
+
+    #[pb(index = 5)]
+    pub sorts: RepeatedSortPB,
+}
+
+impl GridSettingPB {
+    /// Builds the settings payload for a view's current layout, attaching `board_group_field_id`/
+    /// `calendar_date_field_id` (when set) to their matching entry in `layouts` so the frontend can
+    /// render that layout's configuration without a separate round-trip.
+    pub fn new(
+        layout_type: GridLayout,
+        filters: RepeatedFilterPB,
+        group_configurations: RepeatedGroupConfigurationPB,
+        sorts: RepeatedSortPB,
+        board_group_field_id: Option<String>,
+        calendar_date_field_id: Option<String>,
+    ) -> Self {
+        let layouts = GridLayout::iter()
+            .map(|ty| match ty {
+                GridLayout::Board if board_group_field_id.is_some() => {
+                    GridLayoutPB::board(board_group_field_id.clone().unwrap())
+                }
+                GridLayout::Calendar if calendar_date_field_id.is_some() => {
+                    GridLayoutPB::calendar(calendar_date_field_id.clone().unwrap())
+                }
+                ty => GridLayoutPB::bare(ty),
+            })
+            .collect();
+
+        GridSettingPB {
+            layouts,
+            layout_type,
+            filters,
+            group_configurations,
+            sorts,
+        }
+    }
 }
 
 #[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
 pub struct GridLayoutPB {
     #[pb(index = 1)]
     ty: GridLayout,
+
+    #[pb(index = 2, one_of)]
+    setting: Option<GridLayoutSettingPB>,
 }
 
 impl GridLayoutPB {
     pub fn all() -> Vec<GridLayoutPB> {
-        let mut layouts = vec![];
-        for layout_ty in GridLayout::iter() {
-            layouts.push(GridLayoutPB { ty: layout_ty })
+        GridLayout::iter().map(GridLayoutPB::bare).collect()
+    }
+
+    fn bare(ty: GridLayout) -> Self {
+        GridLayoutPB { ty, setting: None }
+    }
+
+    pub fn board(group_field_id: String) -> Self {
+        GridLayoutPB {
+            ty: GridLayout::Board,
+            setting: Some(GridLayoutSettingPB {
+                board: Some(BoardLayoutSettingPB { group_field_id }),
+                calendar: None,
+            }),
         }
+    }
 
-        layouts
+    pub fn calendar(date_field_id: String) -> Self {
+        GridLayoutPB {
+            ty: GridLayout::Calendar,
+            setting: Some(GridLayoutSettingPB {
+                board: None,
+                calendar: Some(CalendarLayoutSettingPB { date_field_id }),
+            }),
+        }
     }
 }
 
+/// The layout-specific config for a [GridLayoutPB], built via `GridLayoutPB::board`/`::calendar` to keep it in sync with `ty`.
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct GridLayoutSettingPB {
+    #[pb(index = 1, one_of)]
+    board: Option<BoardLayoutSettingPB>,
+
+    #[pb(index = 2, one_of)]
+    calendar: Option<CalendarLayoutSettingPB>,
+}
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct BoardLayoutSettingPB {
+    #[pb(index = 1)]
+    pub group_field_id: String,
+}
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct CalendarLayoutSettingPB {
+    #[pb(index = 1)]
+    pub date_field_id: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum, EnumIter)]
 #[repr(u8)]
 pub enum GridLayout {
     Table = 0,
     Board = 1,
+    Calendar = 2,
 }
 
 impl std::default::Default for GridLayout {
@@ -61,6 +146,7 @@ impl std::convert::From<LayoutRevision> for GridLayout {
         match rev {
             LayoutRevision::Table => GridLayout::Table,
             LayoutRevision::Board => GridLayout::Board,
+            LayoutRevision::Calendar => GridLayout::Calendar,
         }
     }
 }
@@ -70,6 +156,7 @@ impl std::convert::From<GridLayout> for LayoutRevision {
         match layout {
             GridLayout::Table => LayoutRevision::Table,
             GridLayout::Board => LayoutRevision::Board,
+            GridLayout::Calendar => LayoutRevision::Calendar,
         }
     }
 }
@@ -93,6 +180,12 @@ pub struct GridSettingChangesetPB {
 
     #[pb(index = 6, one_of)]
     pub delete_group: Option<DeleteGroupPayloadPB>,
+
+    #[pb(index = 7, one_of)]
+    pub insert_sort: Option<AlterSortPayloadPB>,
+
+    #[pb(index = 8, one_of)]
+    pub delete_sort: Option<DeleteSortPayloadPB>,
 }
 
 impl TryInto<GridSettingChangesetParams> for GridSettingChangesetPB {
@@ -103,23 +196,61 @@ impl TryInto<GridSettingChangesetParams> for GridSettingChangesetPB {
             .map_err(|_| ErrorCode::ViewIdInvalid)?
             .0;
 
+        let mut filter_ids = HashSet::new();
+        let mut group_ids = HashSet::new();
+        let mut sort_ids = HashSet::new();
+
         let insert_filter = match self.insert_filter {
             None => None,
-            Some(payload) => Some(payload.try_into()?),
+            Some(payload) => {
+                let params: AlterFilterParams = payload.try_into()?;
+                insert_unique_setting_id(&mut filter_ids, params.filter_id.clone())?;
+                Some(params)
+            }
         };
 
         let delete_filter = match self.delete_filter {
             None => None,
-            Some(payload) => Some(payload.try_into()?),
+            Some(payload) => {
+                let params: DeleteFilterParams = payload.try_into()?;
+                insert_unique_setting_id(&mut filter_ids, Some(params.filter_id.clone()))?;
+                Some(params)
+            }
         };
 
         let insert_group = match self.insert_group {
-            Some(payload) => Some(payload.try_into()?),
+            Some(payload) => {
+                let params: InsertGroupParams = payload.try_into()?;
+                insert_unique_setting_id(&mut group_ids, Some(params.field_id.clone()))?;
+                Some(params)
+            }
             None => None,
         };
 
         let delete_group = match self.delete_group {
-            Some(payload) => Some(payload.try_into()?),
+            Some(payload) => {
+                let params: DeleteGroupParams = payload.try_into()?;
+                insert_unique_setting_id(&mut group_ids, Some(params.group_id.clone()))?;
+                Some(params)
+            }
+            None => None,
+        };
+
+        let insert_sort = match self.insert_sort {
+            Some(payload) => {
+                let params: AlterSortParams = payload.try_into()?;
+                insert_unique_setting_id(&mut sort_ids, params.sort_id.clone())?;
+                Some(params)
+            }
+            None => None,
+        };
+
+        let delete_sort = match self.delete_sort {
+            Some(payload) => {
+                let params: DeleteSortParams = payload.try_into()?;
+                insert_unique_setting_id(&mut sort_ids, Some(params.sort_id.clone()))?;
+                Some(params)
+            }
             None => None,
         };
 
@@ -130,10 +261,13 @@ impl TryInto<GridSettingChangesetParams> for GridSettingChangesetPB {
             delete_filter,
             insert_group,
             delete_group,
+            insert_sort,
+            delete_sort,
         })
     }
 }
 
+#[derive(Debug)]
 pub struct GridSettingChangesetParams {
     pub grid_id: String,
     pub layout_type: LayoutRevision,
@@ -141,10 +275,118 @@ pub struct GridSettingChangesetParams {
     pub delete_filter: Option<DeleteFilterParams>,
     pub insert_group: Option<InsertGroupParams>,
     pub delete_group: Option<DeleteGroupParams>,
+    pub insert_sort: Option<AlterSortParams>,
+    pub delete_sort: Option<DeleteSortParams>,
 }
 
 impl GridSettingChangesetParams {
     pub fn is_filter_changed(&self) -> bool {
         self.insert_filter.is_some() || self.delete_filter.is_some()
     }
+
+    pub fn is_sort_changed(&self) -> bool {
+        self.insert_sort.is_some() || self.delete_sort.is_some()
+    }
+}
+
+/// Like an IDL compiler rejecting a duplicate field/variant id, within its own category.
+fn insert_unique_setting_id(setting_ids: &mut HashSet<String>, id: Option<String>) -> Result<(), ErrorCode> {
+    if let Some(id) = id {
+        if !setting_ids.insert(id) {
+            return Err(ErrorCode::DuplicateSettingId);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duplicate_id_within_same_category_is_rejected() {
+        let mut filter_ids = HashSet::new();
+        insert_unique_setting_id(&mut filter_ids, Some("42".to_owned())).unwrap();
+        let result = insert_unique_setting_id(&mut filter_ids, Some("42".to_owned()));
+        assert_eq!(result, Err(ErrorCode::DuplicateSettingId));
+    }
+
+    #[test]
+    fn same_id_across_categories_is_not_rejected() {
+        let mut filter_ids = HashSet::new();
+        let mut group_ids = HashSet::new();
+        insert_unique_setting_id(&mut filter_ids, Some("42".to_owned())).unwrap();
+        let result = insert_unique_setting_id(&mut group_ids, Some("42".to_owned()));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn changeset_with_duplicate_sort_id_is_rejected() {
+        let changeset = GridSettingChangesetPB {
+            grid_id: "grid-1".to_owned(),
+            insert_sort: Some(AlterSortPayloadPB {
+                view_id: "grid-1".to_owned(),
+                sort_id: Some("sort-1".to_owned()),
+                field_id: "field-1".to_owned(),
+                condition: SortConditionPB::Ascending,
+            }),
+            delete_sort: Some(DeleteSortPayloadPB {
+                view_id: "grid-1".to_owned(),
+                sort_id: "sort-1".to_owned(),
+                field_id: "field-2".to_owned(),
+            }),
+            ..Default::default()
+        };
+
+        let result: Result<GridSettingChangesetParams, ErrorCode> = changeset.try_into();
+        assert_eq!(result.unwrap_err(), ErrorCode::DuplicateSettingId);
+    }
+
+    #[test]
+    fn valid_changeset_converts_successfully() {
+        let changeset = GridSettingChangesetPB {
+            grid_id: "grid-1".to_owned(),
+            insert_sort: Some(AlterSortPayloadPB {
+                view_id: "grid-1".to_owned(),
+                sort_id: Some("sort-1".to_owned()),
+                field_id: "field-1".to_owned(),
+                condition: SortConditionPB::Ascending,
+            }),
+            delete_sort: Some(DeleteSortPayloadPB {
+                view_id: "grid-1".to_owned(),
+                sort_id: "sort-2".to_owned(),
+                field_id: "field-2".to_owned(),
+            }),
+            ..Default::default()
+        };
+
+        let params: GridSettingChangesetParams = changeset.try_into().unwrap();
+        assert!(params.is_sort_changed());
+
+        let empty_changeset = GridSettingChangesetPB {
+            grid_id: "grid-1".to_owned(),
+            ..Default::default()
+        };
+        let params: GridSettingChangesetParams = empty_changeset.try_into().unwrap();
+        assert!(!params.is_sort_changed());
+    }
+
+    #[test]
+    fn grid_setting_pb_attaches_layout_setting_to_matching_entry() {
+        let setting = GridSettingPB::new(
+            GridLayout::Board,
+            RepeatedFilterPB::default(),
+            RepeatedGroupConfigurationPB::default(),
+            RepeatedSortPB::default(),
+            Some("field-1".to_owned()),
+            Some("field-2".to_owned()),
+        );
+
+        let expected = vec![
+            GridLayoutPB::bare(GridLayout::Table),
+            GridLayoutPB::board("field-1".to_owned()),
+            GridLayoutPB::calendar("field-2".to_owned()),
+        ];
+        assert_eq!(setting.layouts, expected);
+    }
 }
@@ -0,0 +1,111 @@
+use crate::entities::parser::NotEmptyStr;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+use std::convert::TryInto;
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct RepeatedSortPB {
+    #[pb(index = 1)]
+    pub items: Vec<SortPB>,
+}
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct SortPB {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub field_id: String,
+
+    #[pb(index = 3)]
+    pub condition: SortConditionPB,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+pub enum SortConditionPB {
+    Ascending = 0,
+    Descending = 1,
+}
+
+impl std::default::Default for SortConditionPB {
+    fn default() -> Self {
+        SortConditionPB::Ascending
+    }
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct AlterSortPayloadPB {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2, one_of)]
+    pub sort_id: Option<String>,
+
+    #[pb(index = 3)]
+    pub field_id: String,
+
+    #[pb(index = 4)]
+    pub condition: SortConditionPB,
+}
+
+#[derive(Debug, Clone)]
+pub struct AlterSortParams {
+    pub view_id: String,
+    pub sort_id: Option<String>,
+    pub field_id: String,
+    pub condition: SortConditionPB,
+}
+
+impl TryInto<AlterSortParams> for AlterSortPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<AlterSortParams, Self::Error> {
+        let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdInvalid)?.0;
+        let field_id = NotEmptyStr::parse(self.field_id)
+            .map_err(|_| ErrorCode::UnexpectedEmptyString)?
+            .0;
+
+        Ok(AlterSortParams {
+            view_id,
+            sort_id: self.sort_id,
+            field_id,
+            condition: self.condition,
+        })
+    }
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct DeleteSortPayloadPB {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub sort_id: String,
+
+    #[pb(index = 3)]
+    pub field_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteSortParams {
+    pub view_id: String,
+    pub sort_id: String,
+    pub field_id: String,
+}
+
+impl TryInto<DeleteSortParams> for DeleteSortPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<DeleteSortParams, Self::Error> {
+        let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdInvalid)?.0;
+        let sort_id = NotEmptyStr::parse(self.sort_id)
+            .map_err(|_| ErrorCode::UnexpectedEmptyString)?
+            .0;
+
+        Ok(DeleteSortParams {
+            view_id,
+            sort_id,
+            field_id: self.field_id,
+        })
+    }
+}